@@ -3,8 +3,10 @@ use clap::Parser;
 use colored::Colorize;
 use std::fs::{self, DirEntry};
 use std::io::{self, BufRead};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::mpsc::channel;
+use std::time::Duration;
 
 #[derive(Debug, Parser)]
 struct Args {
@@ -20,11 +22,35 @@ struct Args {
     #[clap(short, long, default_value_t = false)]
     release: bool,
 
+    /// Run the project's test suite
+    #[clap(short, long, default_value_t = false)]
+    test: bool,
+
+    /// Re-run the selected action(s) whenever a source file changes
+    #[clap(short, long, default_value_t = false)]
+    watch: bool,
+
     /// Verbose output
     #[clap(short, long, default_value_t = false)]
     verbose: bool,
 }
 
+/// Directories whose changes never trigger a `--watch` rebuild (they are
+/// still watched; their events are dropped). Includes build/test-tool output dirs that get
+/// rewritten as a side effect of the very action `--watch` triggers
+/// (e.g. pytest's `.pytest_cache`), to avoid an infinite rebuild loop.
+const WATCH_IGNORE_DIRS: [&str; 9] = [
+    "target",
+    "node_modules",
+    ".git",
+    ".pytest_cache",
+    "__pycache__",
+    "dist",
+    "build",
+    ".mypy_cache",
+    ".ruff_cache",
+];
+
 /// Projects for work with special build commands
 const WORK_PROJECTS: [&str; 5] = [
     "hotshot",
@@ -38,6 +64,8 @@ const WORK_PROJECTS: [&str; 5] = [
 enum ProjectType {
     Rust,
     Python,
+    Node,
+    Go,
 }
 
 impl std::fmt::Display for ProjectType {
@@ -45,6 +73,8 @@ impl std::fmt::Display for ProjectType {
         match self {
             ProjectType::Rust => write!(f, "ProjectType(Rust)"),
             ProjectType::Python => write!(f, "ProjectType(Python)"),
+            ProjectType::Node => write!(f, "ProjectType(Node)"),
+            ProjectType::Go => write!(f, "ProjectType(Go)"),
         }
     }
 }
@@ -78,46 +108,45 @@ impl Project {
     pub fn build(&self, release: bool) -> Result<()> {
         match self.project_type {
             ProjectType::Rust => self.build_rust(release),
-            ProjectType::Python => {
-                bail!("Cannot build this project type yet.")
-            }
+            ProjectType::Python => self.build_python(),
+            ProjectType::Node => self.build_node(),
+            ProjectType::Go => self.build_go(),
         }
     }
 
     pub fn format(&self) -> Result<()> {
         match self.project_type {
             ProjectType::Rust => self.format_rust(),
-            ProjectType::Python => {
-                bail!("Cannot format this project type yet.")
-            }
+            ProjectType::Python => self.format_python(),
+            ProjectType::Node => self.format_node(),
+            ProjectType::Go => self.format_go(),
+        }
+    }
+
+    pub fn test(&self) -> Result<()> {
+        match self.project_type {
+            ProjectType::Rust => self.run_cmd("cargo", vec!["test"]),
+            ProjectType::Python => self.run_cmd("pytest", vec![]),
+            ProjectType::Node => self.run_cmd("npm", vec!["test"]),
+            ProjectType::Go => self.run_cmd("go", vec!["test", "./..."]),
         }
     }
 
     fn format_rust(&self) -> Result<()> {
-        let cmds = match self.project_type {
-            ProjectType::Rust => {
-                if self.work && self.name.as_str() == "hotshot" {
-                    vec!["just", "async-std", "fmt_lint"]
-                } else {
-                    vec!["cargo", "fmt"]
-                }
-            }
-            ProjectType::Python => bail!("Not supported yet."),
+        let cmds = if self.work && self.name.as_str() == "hotshot" {
+            vec!["just", "async-std", "fmt_lint"]
+        } else {
+            vec!["cargo", "fmt"]
         };
 
         self.run_cmd(cmds[0], cmds[1..].to_vec())
     }
 
     fn build_rust(&self, release: bool) -> Result<()> {
-        let mut cmds = match self.project_type {
-            ProjectType::Rust => {
-                if self.work && self.name.as_str() == "hotshot" {
-                    vec!["just", "async-std", "build"]
-                } else {
-                    vec!["cargo", "build"]
-                }
-            }
-            ProjectType::Python => bail!("Not supported yet."),
+        let mut cmds = if self.work && self.name.as_str() == "hotshot" {
+            vec!["just", "async-std", "build"]
+        } else {
+            vec!["cargo", "build"]
         };
 
         if release {
@@ -127,6 +156,35 @@ impl Project {
         self.run_cmd(cmds[0], cmds[1..].to_vec())
     }
 
+    fn format_python(&self) -> Result<()> {
+        self.run_cmd("ruff", vec!["format", "."])?;
+        self.run_cmd("black", vec!["."])
+    }
+
+    fn build_python(&self) -> Result<()> {
+        println!(
+            "{}",
+            "Python projects have nothing to build; skipping.".blue()
+        );
+        Ok(())
+    }
+
+    fn format_node(&self) -> Result<()> {
+        self.run_cmd("prettier", vec!["--write", "."])
+    }
+
+    fn build_node(&self) -> Result<()> {
+        self.run_cmd("npm", vec!["run", "build"])
+    }
+
+    fn format_go(&self) -> Result<()> {
+        self.run_cmd("gofmt", vec!["-w", "."])
+    }
+
+    fn build_go(&self) -> Result<()> {
+        self.run_cmd("go", vec!["build", "./..."])
+    }
+
     fn run_cmd(&self, command_name: &str, args: Vec<&str>) -> Result<()> {
         let mut child = Command::new(command_name)
             .args(args)
@@ -213,6 +271,8 @@ fn detect_project_type(paths: &Vec<DirEntry>) -> Option<ProjectType> {
                 Some(s) => match s.to_lowercase().as_str() {
                     "cargo.toml" => return Some(ProjectType::Rust),
                     "requirements.txt" | "pyproject.toml" => return Some(ProjectType::Python),
+                    "package.json" => return Some(ProjectType::Node),
+                    "go.mod" => return Some(ProjectType::Go),
                     _ => {}
                 },
                 None => {}
@@ -223,6 +283,8 @@ fn detect_project_type(paths: &Vec<DirEntry>) -> Option<ProjectType> {
             match os_str.to_str() {
                 Some("rs") => return Some(ProjectType::Rust),
                 Some("py") => return Some(ProjectType::Python),
+                Some("go") => return Some(ProjectType::Go),
+                Some("js") | Some("ts") => return Some(ProjectType::Node),
                 _ => {}
             }
         }
@@ -235,6 +297,76 @@ fn find_project_name(current_dir: &PathBuf) -> &str {
     current_dir.file_name().unwrap().to_str().unwrap()
 }
 
+/// Runs whichever of `--build`/`--format`/`--test` were requested, in that
+/// order, printing the project summary first when `--verbose` is set.
+fn run_selected_actions(project: &Project, args: &Args) -> Result<()> {
+    if args.verbose {
+        println!("{}", format!("{project}").blue());
+    }
+
+    if args.build {
+        if args.verbose {
+            println!("{}", "Initiating build".blue());
+        }
+        project.build(args.release)?;
+    }
+
+    if args.format {
+        project.format()?;
+    }
+
+    if args.test {
+        if args.verbose {
+            println!("{}", "Running tests".blue());
+        }
+        project.test()?;
+    }
+
+    Ok(())
+}
+
+/// Whether `path` lives under a directory we never want to watch or rebuild for.
+fn is_ignored_watch_path(path: &Path) -> bool {
+    path.components().any(|c| {
+        c.as_os_str()
+            .to_str()
+            .is_some_and(|name| WATCH_IGNORE_DIRS.contains(&name))
+    })
+}
+
+/// Re-runs the selected action(s) every time a source file changes, using a
+/// ~200ms debounce window so a burst of edits (e.g. a save-all) only
+/// triggers one rebuild.
+fn watch_and_run(project: &Project, args: &Args) -> Result<()> {
+    use notify::{RecursiveMode, Watcher};
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(Path::new("."), RecursiveMode::Recursive)?;
+
+    println!("{}", "Watching for changes (Ctrl+C to stop)...".blue());
+    run_selected_actions(project, args)?;
+
+    while let Ok(event) = rx.recv() {
+        let Ok(event) = event else { continue };
+        if !event.paths.iter().any(|p| !is_ignored_watch_path(p)) {
+            continue;
+        }
+
+        // Debounce: swallow anything else that arrives in the next window
+        // so one burst of edits triggers a single rebuild.
+        std::thread::sleep(Duration::from_millis(200));
+        while rx.try_recv().is_ok() {}
+
+        println!("{}", "Change detected, re-running...".blue());
+        if let Err(e) = run_selected_actions(project, args) {
+            eprintln!("{}", e.to_string().bright_red());
+        }
+    }
+
+    Ok(())
+}
+
 fn main() -> Result<()> {
     let args = Args::parse();
 
@@ -250,25 +382,15 @@ fn main() -> Result<()> {
     // Get the project name
     let project = if let Some(name) = detect_work_project(&current_dir, &paths) {
         Project::new(&name, true, ProjectType::Rust)
+    } else if let Some(typ) = detect_project_type(&paths) {
+        Project::new(find_project_name(&current_dir), false, typ)
     } else {
-        if let Some(typ) = detect_project_type(&paths) {
-            Project::new(&find_project_name(&current_dir), false, typ)
-        } else {
-            bail!("Couldn't detect project type, or go an invalid project type.");
-        }
+        bail!("Couldn't detect project type, or go an invalid project type.");
     };
 
-    if args.build {
-        if args.verbose {
-            println!("{}", format!("{}", project).blue());
-            println!("{}", format!("{}", "Initiating build".blue()));
-        }
-        project.build(args.release)?;
-    }
-
-    if args.format {
-        project.format()?;
+    if args.watch {
+        watch_and_run(&project, &args)
+    } else {
+        run_selected_actions(&project, &args)
     }
-
-    Ok(())
 }
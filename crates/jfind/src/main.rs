@@ -1,11 +1,25 @@
-use std::{fs, path::PathBuf};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
 
-use anyhow::{bail, ensure, Result};
-use clap::Parser;
+use anyhow::{ensure, Context, Result};
+use clap::{Parser, ValueEnum};
 use colored::Colorize;
-use ignore::gitignore::GitignoreBuilder;
+use globset::{Glob, GlobMatcher};
+use ignore::{WalkBuilder, WalkState};
 use rayon::prelude::*;
-use walkdir::WalkDir;
+use regex::{Regex, RegexBuilder};
+
+/// How many `--exec` invocations we allow to run at once, so a huge match
+/// set doesn't spawn thousands of processes simultaneously.
+const MAX_EXEC_CONCURRENCY: usize = 8;
 
 /// The `jfind` command is a streamlined find command. You can simply do
 /// `jfind query` and it'll recurisvely search the current directory for files
@@ -24,15 +38,252 @@ struct Args {
     #[clap(short, long, default_value_t = false)]
     case_sensitive: bool,
 
-    /// Whether or not to ignore files in .gitignore
+    /// Include files that .gitignore files would exclude. .git/info/exclude,
+    /// global git excludes, and .ignore files still apply.
+    #[clap(short, long, alias = "ignore-gitingore", default_value_t = false)]
+    ignore_gitignore: bool,
+
+    /// Treat the query as a regular expression, highlighting every
+    /// non-overlapping match.
+    #[clap(short = 'e', long, default_value_t = false, conflicts_with = "glob")]
+    regex: bool,
+
+    /// Treat the query as a shell glob, matching against the whole
+    /// path/line.
     #[clap(short, long, default_value_t = false)]
-    ignore_gitingore: bool,
+    glob: bool,
+
+    /// Only show entries of the given type(s). May be passed more than once.
+    #[clap(short = 't', long = "type", value_enum, num_args = 1..)]
+    entry_type: Vec<EntryType>,
+
+    /// Only show entries with one of the given extensions (without the
+    /// leading dot). May be passed more than once.
+    #[clap(short = 'x', long, num_args = 1..)]
+    extension: Vec<String>,
+
+    /// Only show entries matching a size comparison, e.g. `+1M` or `-500k`.
+    #[clap(long)]
+    size: Option<String>,
+
+    /// Run a command for each match, in parallel. Supports the placeholders
+    /// `{}` (full path), `{/}` (basename), `{//}` (parent dir), `{.}` (path
+    /// without extension), and `{/.}` (basename without extension). If none
+    /// of these appear, the path is appended as the command's last argument.
+    #[clap(short = 'X', long = "exec", num_args = 1.., allow_hyphen_values = true, value_name = "cmd")]
+    exec: Option<Vec<String>>,
 
     /// The query to search for
     query: String,
 }
 
-fn check_and_colorize_match(path: &str, query: &str, case_sensitive: bool) -> Option<String> {
+/// The kinds of filesystem entries `--type`/`-t` can restrict a search to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum EntryType {
+    File,
+    Dir,
+    Symlink,
+    Exec,
+}
+
+/// Whether a parsed `--size` threshold must be exceeded, undershot, or matched exactly.
+#[derive(Debug, Clone, Copy)]
+enum SizeCmp {
+    Greater,
+    Less,
+    Exact,
+}
+
+/// A parsed `--size` predicate, e.g. `+1M` becomes `{ cmp: Greater, bytes: 1_048_576 }`.
+#[derive(Debug, Clone, Copy)]
+struct SizePredicate {
+    cmp: SizeCmp,
+    bytes: u64,
+}
+
+impl SizePredicate {
+    fn matches(&self, size: u64) -> bool {
+        match self.cmp {
+            SizeCmp::Greater => size > self.bytes,
+            SizeCmp::Less => size < self.bytes,
+            SizeCmp::Exact => size == self.bytes,
+        }
+    }
+}
+
+fn parse_size(spec: &str) -> Result<SizePredicate> {
+    let (cmp, rest) = match spec.strip_prefix('+') {
+        Some(rest) => (SizeCmp::Greater, rest),
+        None => match spec.strip_prefix('-') {
+            Some(rest) => (SizeCmp::Less, rest),
+            None => (SizeCmp::Exact, spec),
+        },
+    };
+
+    let (digits, multiplier) = match rest.chars().last() {
+        Some('b') | Some('B') => (&rest[..rest.len() - 1], 1),
+        Some('k') | Some('K') => (&rest[..rest.len() - 1], 1024),
+        Some('M') => (&rest[..rest.len() - 1], 1024 * 1024),
+        Some('G') => (&rest[..rest.len() - 1], 1024 * 1024 * 1024),
+        _ => (rest, 1),
+    };
+
+    let value: u64 = digits
+        .parse()
+        .with_context(|| format!("invalid --size value {spec:?}"))?;
+
+    Ok(SizePredicate {
+        cmp,
+        bytes: value * multiplier,
+    })
+}
+
+/// The combined `--type`/`--extension`/`--size` filters, checked against each
+/// walked entry before it's handed to the [`Matcher`].
+struct Filters {
+    types: Vec<EntryType>,
+    extensions: HashSet<String>,
+    size: Option<SizePredicate>,
+}
+
+impl Filters {
+    fn new(args: &Args) -> Result<Self> {
+        let size = args.size.as_deref().map(parse_size).transpose()?;
+
+        Ok(Self {
+            types: args.entry_type.clone(),
+            extensions: args
+                .extension
+                .iter()
+                .map(|e| e.trim_start_matches('.').to_lowercase())
+                .collect(),
+            size,
+        })
+    }
+
+    fn matches(&self, entry: &ignore::DirEntry) -> bool {
+        if !self.types.is_empty() {
+            let is_exec = |ft: &fs::FileType| -> bool {
+                if !ft.is_file() {
+                    return false;
+                }
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::PermissionsExt;
+                    entry
+                        .metadata()
+                        .map(|m| m.permissions().mode() & 0o111 != 0)
+                        .unwrap_or(false)
+                }
+                #[cfg(not(unix))]
+                {
+                    false
+                }
+            };
+
+            let type_matches = entry.file_type().is_some_and(|ft| {
+                self.types.iter().any(|t| match t {
+                    EntryType::File => ft.is_file(),
+                    EntryType::Dir => ft.is_dir(),
+                    EntryType::Symlink => ft.is_symlink(),
+                    EntryType::Exec => is_exec(&ft),
+                })
+            });
+
+            if !type_matches {
+                return false;
+            }
+        }
+
+        if !self.extensions.is_empty() {
+            let ext_matches = entry
+                .path()
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| self.extensions.contains(&e.to_lowercase()));
+
+            if !ext_matches {
+                return false;
+            }
+        }
+
+        if let Some(predicate) = &self.size {
+            let size_matches = entry
+                .metadata()
+                .is_ok_and(|m| predicate.matches(m.len()));
+
+            if !size_matches {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// The different ways `jfind` can match a query against a candidate string.
+/// Defaults to a plain, case-foldable substring search.
+enum Matcher {
+    Substring { query: String, case_sensitive: bool },
+    Regex(Regex),
+    Glob(GlobMatcher),
+}
+
+impl Matcher {
+    fn new(args: &Args) -> Result<Self> {
+        if args.regex {
+            let regex = RegexBuilder::new(&args.query)
+                .case_insensitive(!args.case_sensitive)
+                .build()?;
+            Ok(Matcher::Regex(regex))
+        } else if args.glob {
+            Ok(Matcher::Glob(Glob::new(&args.query)?.compile_matcher()))
+        } else {
+            Ok(Matcher::Substring {
+                query: args.query.clone(),
+                case_sensitive: args.case_sensitive,
+            })
+        }
+    }
+
+    /// Returns `text` with every match highlighted, or `None` if it doesn't match at all.
+    fn highlight(&self, text: &str) -> Option<String> {
+        match self {
+            Matcher::Substring {
+                query,
+                case_sensitive,
+            } => check_and_colorize_substring_match(text, query, *case_sensitive),
+            Matcher::Regex(regex) => {
+                let mut matches = regex.find_iter(text).peekable();
+                matches.peek()?;
+
+                let mut out = String::new();
+                let mut last_end = 0;
+                for m in matches {
+                    out.push_str(&text[last_end..m.start()]);
+                    out.push_str(&m.as_str().bright_red().to_string());
+                    last_end = m.end();
+                }
+                out.push_str(&text[last_end..]);
+
+                Some(out)
+            }
+            Matcher::Glob(glob) => {
+                if glob.is_match(text) {
+                    Some(text.bright_red().to_string())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn check_and_colorize_substring_match(
+    path: &str,
+    query: &str,
+    case_sensitive: bool,
+) -> Option<String> {
     let start = if !case_sensitive {
         path.to_lowercase().find(&query.to_lowercase())
     } else {
@@ -52,62 +303,89 @@ fn check_and_colorize_match(path: &str, query: &str, case_sensitive: bool) -> Op
     }
 }
 
-fn find_in_directory(directory: String, args: Args) -> Result<String> {
-    let mut ignore_builder = GitignoreBuilder::new(&directory);
-    if args.ignore_gitingore {
-        if let Some(e) = ignore_builder.add(format!("{}/.gitignore", directory)) {
-            bail!("Error parsing .gitignore: {e}");
-        }
-    }
+/// A single match: the raw, uncolored string `--exec` operates on, paired
+/// with its colorized rendering for normal display.
+#[derive(Debug)]
+struct Match {
+    raw: String,
+    colored: String,
+}
+
+fn find_in_directory(
+    directory: String,
+    args: &Args,
+    matcher: &Matcher,
+    filters: &Filters,
+) -> Result<Vec<Match>> {
+    // `WalkBuilder` already gives us hierarchical .gitignore matching (each
+    // directory's .gitignore applies to its own subtree), .git/info/exclude,
+    // global git excludes, and .ignore files, plus its own threadpool for
+    // parallel traversal via `build_parallel`.
+    let walker = WalkBuilder::new(&directory)
+        .max_depth(Some(args.depth))
+        .git_ignore(!args.ignore_gitignore)
+        .build_parallel();
 
-    let gitignore = ignore_builder.build()?;
-
-    let mut ret = WalkDir::new(directory)
-        .max_depth(args.depth)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .par_bridge()
-        .map(|e| e.path().display().to_string())
-        .filter_map(|path| {
-            if gitignore.matched(&path, false).is_ignore() {
-                return None;
+    let results = Arc::new(Mutex::new(Vec::new()));
+
+    walker.run(|| {
+        let results = Arc::clone(&results);
+
+        Box::new(move |entry| {
+            let Ok(entry) = entry else {
+                return WalkState::Continue;
+            };
+
+            if filters.matches(&entry) {
+                let path = entry.path().display().to_string();
+                if let Some(colored) = matcher.highlight(&path) {
+                    results.lock().unwrap().push(Match {
+                        raw: path,
+                        colored,
+                    });
+                }
             }
 
-            check_and_colorize_match(&path, &args.query, args.case_sensitive)
+            WalkState::Continue
         })
-        .collect::<Vec<String>>();
+    });
+
+    let mut ret = Arc::try_unwrap(results)
+        .expect("all walker threads have finished")
+        .into_inner()
+        .unwrap();
 
-    ret.sort();
+    ret.sort_by(|a, b| a.raw.cmp(&b.raw));
 
-    Ok(ret.join("\n"))
+    Ok(ret)
 }
 
-fn find_in_file(filename: String, args: Args) -> Result<String> {
+fn find_in_file(filename: String, matcher: &Matcher) -> Result<Vec<Match>> {
     let contents = fs::read_to_string(&filename)?;
     let mut ret = contents
         .lines()
         .enumerate()
         .filter_map(|(i, line)| {
-            if let Some(colored_line) =
-                check_and_colorize_match(line, &args.query, args.case_sensitive)
-            {
-                Some((i, format!("{}:{}: {}", filename, i, colored_line)))
-            } else {
-                None
-            }
+            matcher.highlight(line).map(|colored_line| {
+                (
+                    i,
+                    Match {
+                        raw: filename.clone(),
+                        colored: format!("{}:{}: {}", filename, i, colored_line),
+                    },
+                )
+            })
         })
-        .collect::<Vec<(usize, String)>>();
+        .collect::<Vec<(usize, Match)>>();
 
-    ret.sort_by(|a, b| a.0.cmp(&b.0));
+    // Sort on the line index, not the colorized "file:N: line" string — a
+    // lexicographic sort over that string puts "...:10:" before "...:2:".
+    ret.sort_by_key(|(i, _)| *i);
 
-    Ok(ret
-        .into_iter()
-        .map(|(_, line)| line)
-        .collect::<Vec<String>>()
-        .join("\n"))
+    Ok(ret.into_iter().map(|(_, m)| m).collect())
 }
 
-fn find(args: Args) -> Result<String> {
+fn find(args: &Args) -> Result<Vec<Match>> {
     let mut in_ = PathBuf::from(args.in_.clone());
     let mut metadata = fs::metadata(&in_)?;
 
@@ -122,15 +400,126 @@ fn find(args: Args) -> Result<String> {
         in_
     );
 
+    let matcher = Matcher::new(args)?;
+    let filters = Filters::new(args)?;
+
     if metadata.is_dir() {
-        find_in_directory(in_.display().to_string(), args)
+        find_in_directory(in_.display().to_string(), args, &matcher, &filters)
     } else {
-        find_in_file(in_.display().to_string(), args)
+        find_in_file(in_.display().to_string(), &matcher)
+    }
+}
+
+/// Substitutes fd-style placeholder tokens in `template` with values derived
+/// from `path`. Appends `path` as a final argument when no placeholder is
+/// present.
+fn build_exec_command(template: &[String], path: &str) -> Vec<String> {
+    let p = Path::new(path);
+    let basename = p
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or(path)
+        .to_string();
+    let parent = p
+        .parent()
+        .map(|d| d.display().to_string())
+        .unwrap_or_default();
+    let without_ext = p.with_extension("").display().to_string();
+    let basename_without_ext = p
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(path)
+        .to_string();
+
+    let mut saw_placeholder = false;
+    let mut command = template
+        .iter()
+        .map(|token| {
+            let mut replaced = token.clone();
+            for (placeholder, value) in [
+                ("{//}", parent.as_str()),
+                ("{/.}", basename_without_ext.as_str()),
+                ("{.}", without_ext.as_str()),
+                ("{/}", basename.as_str()),
+                ("{}", path),
+            ] {
+                if replaced.contains(placeholder) {
+                    saw_placeholder = true;
+                    replaced = replaced.replace(placeholder, value);
+                }
+            }
+            replaced
+        })
+        .collect::<Vec<String>>();
+
+    if !saw_placeholder {
+        command.push(path.to_string());
     }
+
+    command
 }
 
-fn main() {
+/// Runs `template` once per entry in `matches`, bounded to
+/// `MAX_EXEC_CONCURRENCY` concurrent children. Each invocation's stdout and
+/// stderr are printed together, so output from concurrent runs can't
+/// interleave. Returns whether every invocation succeeded.
+fn run_exec(template: &[String], matches: &[Match]) -> Result<bool> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(MAX_EXEC_CONCURRENCY)
+        .build()?;
+
+    let all_succeeded = AtomicBool::new(true);
+    // Guards stdout+stderr together so one invocation's output is printed as
+    // a single uninterruptible unit, instead of two separately-locked
+    // print!/eprint! calls that another worker's output could land between.
+    let print_lock = Mutex::new(());
+
+    pool.install(|| {
+        matches.par_iter().for_each(|m| {
+            let command = build_exec_command(template, &m.raw);
+            let output = Command::new(&command[0])
+                .args(&command[1..])
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .output();
+
+            match output {
+                Ok(output) => {
+                    let _guard = print_lock.lock().unwrap();
+                    print!("{}", String::from_utf8_lossy(&output.stdout));
+                    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+                    if !output.status.success() {
+                        all_succeeded.store(false, Ordering::Relaxed);
+                    }
+                }
+                Err(e) => {
+                    let _guard = print_lock.lock().unwrap();
+                    eprintln!("{}: {e}", command.join(" ").bright_red());
+                    all_succeeded.store(false, Ordering::Relaxed);
+                }
+            }
+        });
+    });
+
+    Ok(all_succeeded.load(Ordering::Relaxed))
+}
+
+fn main() -> Result<()> {
     let args = Args::parse();
-    let output = find(args).unwrap();
-    println!("{}", output);
+    let matches = find(&args)?;
+
+    if let Some(template) = &args.exec {
+        if !run_exec(template, &matches)? {
+            std::process::exit(1);
+        }
+    } else {
+        let output = matches
+            .iter()
+            .map(|m| m.colored.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n");
+        println!("{}", output);
+    }
+
+    Ok(())
 }
@@ -1,8 +1,16 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Local};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use colored::Colorize;
 use crossterm::terminal::size;
-use std::{fs, os::unix::fs::MetadataExt};
+use git2::{Repository, Status, StatusOptions};
+use std::{
+    collections::HashMap,
+    fs,
+    os::unix::fs::MetadataExt,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
 use users::{get_group_by_gid, get_user_by_uid};
 
 #[cfg(unix)]
@@ -29,11 +37,199 @@ struct Args {
     #[clap(short, long, default_value_t = false)]
     human: bool,
 
+    /// Show a two-character git status column ahead of each entry
+    #[clap(long, default_value_t = false)]
+    git: bool,
+
+    /// Recursively render the directory hierarchy with tree connectors
+    #[clap(short = 'T', long, default_value_t = false)]
+    tree: bool,
+
+    /// Limit --tree recursion to this many levels deep
+    #[clap(long)]
+    level: Option<usize>,
+
+    /// The key to sort entries by
+    #[clap(long, value_enum, default_value_t = SortKey::Name)]
+    sort: SortKey,
+
+    /// Shorthand for --sort=time
+    #[clap(short = 't', default_value_t = false)]
+    sort_time: bool,
+
+    /// Shorthand for --sort=size
+    #[clap(short = 'S', default_value_t = false)]
+    sort_size: bool,
+
+    /// Reverse the sort order
+    #[clap(short, long, default_value_t = false)]
+    reverse: bool,
+
     /// The path to list
     #[clap(default_value = ".")]
     path: String,
 }
 
+/// The keys `--sort` (or its `-t`/`-S` shorthands) can order entries by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SortKey {
+    Name,
+    Time,
+    Size,
+    Extension,
+}
+
+impl Args {
+    /// Resolves `--sort`, `-t`, and `-S` into the single key that should win:
+    /// the shorthand flags take precedence over `--sort`, matching coreutils.
+    fn sort_key(&self) -> SortKey {
+        if self.sort_time {
+            SortKey::Time
+        } else if self.sort_size {
+            SortKey::Size
+        } else {
+            self.sort
+        }
+    }
+}
+
+/// Sorts `entries_paths` by `key`, breaking ties by name, then reverses the
+/// order if requested. Applied before grid/list layout is chosen so both
+/// honor the same order.
+fn sort_entries(entries_paths: &mut [PathBuf], key: SortKey, reverse: bool) {
+    match key {
+        SortKey::Name => entries_paths.sort(),
+        SortKey::Extension => entries_paths.sort_by(|a, b| {
+            let ext = |p: &PathBuf| p.extension().and_then(|e| e.to_str()).unwrap_or("").to_string();
+            ext(a).cmp(&ext(b)).then_with(|| a.cmp(b))
+        }),
+        SortKey::Time => entries_paths.sort_by(|a, b| {
+            let modified = |p: &PathBuf| {
+                fs::metadata(p)
+                    .and_then(|m| m.modified())
+                    .unwrap_or(SystemTime::UNIX_EPOCH)
+            };
+            // Newest first, like coreutils `ls -t`.
+            modified(b).cmp(&modified(a)).then_with(|| a.cmp(b))
+        }),
+        SortKey::Size => entries_paths.sort_by(|a, b| {
+            let size = |p: &PathBuf| fs::metadata(p).map(|m| m.len()).unwrap_or(0);
+            // Largest first, like coreutils `ls -S`.
+            size(b).cmp(&size(a)).then_with(|| a.cmp(b))
+        }),
+    }
+
+    if reverse {
+        entries_paths.reverse();
+    }
+}
+
+/// Maps each listed entry's staged/unstaged git status to a two-character
+/// column, e.g. `M-` (staged modify), `-?` (untracked), `--` (clean, or not
+/// inside a repository at all).
+struct GitStatuses {
+    workdir: PathBuf,
+    by_path: HashMap<PathBuf, Status>,
+}
+
+impl GitStatuses {
+    /// Opens the repository enclosing `path`, if any. Returns `None` (rather
+    /// than erroring) when `path` isn't inside a git repository, so the
+    /// `--git` column can degrade gracefully to `--`.
+    fn discover(path: &Path) -> Option<Self> {
+        let repo = Repository::discover(path).ok()?;
+        let workdir = repo.workdir()?.to_path_buf();
+
+        let mut opts = StatusOptions::new();
+        opts.include_untracked(true)
+            .include_ignored(true)
+            .recurse_untracked_dirs(true);
+
+        let statuses = repo.statuses(Some(&mut opts)).ok()?;
+        let by_path = statuses
+            .iter()
+            .filter_map(|entry| Some((PathBuf::from(entry.path()?), entry.status())))
+            .collect();
+
+        Some(Self { workdir, by_path })
+    }
+
+    fn column_for(&self, entry: &Path, is_dir: bool) -> String {
+        let Ok(rel) = entry.strip_prefix(&self.workdir) else {
+            return "--".to_string();
+        };
+
+        if is_dir {
+            let combined = self
+                .by_path
+                .iter()
+                .filter(|(p, _)| p.starts_with(rel))
+                .fold(Status::empty(), |acc, (_, s)| acc | *s);
+            Self::render(combined)
+        } else {
+            Self::render(self.by_path.get(rel).copied().unwrap_or_else(Status::empty))
+        }
+    }
+
+    fn render(status: Status) -> String {
+        let index = if status.contains(Status::INDEX_NEW) {
+            'A'
+        } else if status.contains(Status::INDEX_MODIFIED) {
+            'M'
+        } else if status.contains(Status::INDEX_DELETED) {
+            'D'
+        } else if status.contains(Status::INDEX_RENAMED) {
+            'R'
+        } else {
+            '-'
+        };
+
+        let worktree = if status.contains(Status::WT_NEW) {
+            '?'
+        } else if status.contains(Status::WT_MODIFIED) {
+            'M'
+        } else if status.contains(Status::WT_DELETED) {
+            'D'
+        } else if status.contains(Status::WT_RENAMED) {
+            'R'
+        } else if status.contains(Status::IGNORED) {
+            '!'
+        } else {
+            '-'
+        };
+
+        format!("{index}{worktree}")
+    }
+}
+
+/// Colorizes a permission string's `r`/`w`/`x` bits the way modern `ls`
+/// replacements do: reads yellow, writes red, executes green.
+fn colorize_mode(mode: &str) -> String {
+    mode.chars()
+        .map(|c| match c {
+            'r' => c.to_string().yellow().to_string(),
+            'w' => c.to_string().red().to_string(),
+            'x' => c.to_string().green().to_string(),
+            'd' => c.to_string().blue().bold().to_string(),
+            _ => c.to_string().dimmed().to_string(),
+        })
+        .collect::<String>()
+}
+
+/// Colorizes an entry's displayed name by type: directories blue, symlinks
+/// cyan, executables green.
+fn colorize_name(name: &str, metadata: &fs::Metadata, is_symlink: bool) -> String {
+    if is_symlink {
+        name.cyan().to_string()
+    } else if metadata.is_dir() {
+        name.blue().to_string()
+    } else if metadata.permissions().mode() & 0o111 != 0 {
+        name.green().to_string()
+    } else {
+        name.to_string()
+    }
+}
+
 fn get_file_group(metadata: &fs::Metadata) -> Result<String> {
     let gid = metadata.gid();
     let group = get_group_by_gid(gid).context("Attempting to get group by gdi")?;
@@ -107,31 +303,247 @@ fn get_metadata(path: &String) -> Result<String> {
     Ok("".to_string())
 }
 
+/// A single entry's precomputed `--list` columns, gathered once per
+/// directory so width alignment and formatting stay in one place for both
+/// the flat `--list` view and `--tree --list`.
+#[cfg(unix)]
+struct EntryMeta {
+    metadata: fs::Metadata,
+    mode: String,
+    nlink: String,
+    owner: String,
+    group: String,
+    size: String,
+    modified: String,
+}
+
+#[cfg(unix)]
+fn gather_entry_metas(entries_paths: &[PathBuf], args: &Args) -> Vec<EntryMeta> {
+    entries_paths
+        .iter()
+        .map(|entry| {
+            let metadata = fs::metadata(entry).unwrap();
+            let mode = get_mode(&metadata);
+            let nlink = get_nlink(&metadata);
+            let owner = get_file_owner(&metadata).unwrap();
+            let group = get_file_group(&metadata).unwrap();
+            let size = if args.human {
+                get_size_human_readable(&metadata)
+            } else {
+                get_size_bytes(&metadata)
+            };
+            let modified = get_last_modified(&metadata).unwrap();
+
+            EntryMeta {
+                metadata,
+                mode,
+                nlink,
+                owner,
+                group,
+                size,
+                modified,
+            }
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+struct ColumnWidths {
+    nlink: usize,
+    owner: usize,
+    group: usize,
+    size: usize,
+}
+
+#[cfg(unix)]
+fn column_widths(metas: &[EntryMeta]) -> ColumnWidths {
+    ColumnWidths {
+        nlink: metas.iter().map(|m| m.nlink.len()).max().unwrap_or(0),
+        owner: metas.iter().map(|m| m.owner.len()).max().unwrap_or(0),
+        group: metas.iter().map(|m| m.group.len()).max().unwrap_or(0),
+        size: metas.iter().map(|m| m.size.len()).max().unwrap_or(0),
+    }
+}
+
+#[cfg(unix)]
+fn git_column_for(
+    show_git: bool,
+    git_statuses: &Option<GitStatuses>,
+    entry: &Path,
+    is_dir: bool,
+) -> String {
+    if !show_git {
+        return String::new();
+    }
+
+    git_statuses
+        .as_ref()
+        .map(|statuses| {
+            let canonical = fs::canonicalize(entry).unwrap_or_else(|_| entry.to_path_buf());
+            format!("{} ", statuses.column_for(&canonical, is_dir))
+        })
+        .unwrap_or_else(|| "-- ".to_string())
+}
+
+#[cfg(unix)]
+fn format_metadata_block(
+    entry: &Path,
+    meta: &EntryMeta,
+    widths: &ColumnWidths,
+    show_git: bool,
+    git_statuses: &Option<GitStatuses>,
+) -> String {
+    format!(
+        "{}{:<10} {:>width$} {:<owner_width$} {:<group_width$} {:>size_width$} {} ",
+        git_column_for(show_git, git_statuses, entry, meta.metadata.is_dir()),
+        colorize_mode(&meta.mode),
+        meta.nlink,
+        meta.owner,
+        meta.group,
+        meta.size,
+        meta.modified,
+        width = widths.nlink,
+        owner_width = widths.owner,
+        group_width = widths.group,
+        size_width = widths.size,
+    )
+}
+
+/// Reads `dir`'s immediate children, honoring the `--all` hidden-file rule.
+fn read_dir_filtered(dir: &Path, all: bool) -> Result<Vec<PathBuf>> {
+    let all_files = fs::read_dir(dir)?.map(|entry| entry.unwrap().path());
+
+    Ok(if all {
+        all_files.collect()
+    } else {
+        all_files
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| !name.starts_with('.'))
+            })
+            .collect()
+    })
+}
+
+/// Recursively renders `dir` as a tree with eza-style box-drawing
+/// connectors, descending into subdirectories up to `--level` deep.
+#[cfg(unix)]
+fn render_tree(
+    dir: &Path,
+    args: &Args,
+    git_statuses: &Option<GitStatuses>,
+    prefix: &str,
+    depth: usize,
+    output: &mut String,
+) -> Result<()> {
+    if args.level.is_some_and(|max| depth >= max) {
+        return Ok(());
+    }
+
+    let mut entries_paths = match read_dir_filtered(dir, args.all) {
+        Ok(entries_paths) => entries_paths,
+        Err(e) => {
+            output.push_str(&format!("{prefix}└── [error opening directory: {e}]\n"));
+            return Ok(());
+        }
+    };
+    sort_entries(&mut entries_paths, args.sort_key(), args.reverse);
+
+    let metas = args.list.then(|| gather_entry_metas(&entries_paths, args));
+    let widths = metas.as_ref().map(|m| column_widths(m));
+
+    let last_index = entries_paths.len().saturating_sub(1);
+    for (i, entry) in entries_paths.iter().enumerate() {
+        let is_last = i == last_index;
+        let connector = if is_last { "└── " } else { "├── " };
+
+        let symlink_metadata = fs::symlink_metadata(entry)?;
+        let is_symlink = symlink_metadata.is_symlink();
+        let display_metadata = fs::metadata(entry).unwrap_or_else(|_| symlink_metadata.clone());
+
+        let metadata_block = match (&metas, &widths) {
+            (Some(metas), Some(widths)) => {
+                format_metadata_block(entry, &metas[i], widths, args.git, git_statuses)
+            }
+            _ => String::new(),
+        };
+
+        let name = entry.file_name().unwrap().to_str().unwrap();
+        let colored_name = colorize_name(name, &display_metadata, is_symlink);
+
+        output.push_str(&format!("{prefix}{connector}{metadata_block}{colored_name}\n"));
+
+        if display_metadata.is_dir() && !is_symlink {
+            let child_prefix = format!("{prefix}{}", if is_last { "   " } else { "│  " });
+            render_tree(entry, args, git_statuses, &child_prefix, depth + 1, output)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn render_tree(
+    dir: &Path,
+    args: &Args,
+    git_statuses: &Option<GitStatuses>,
+    prefix: &str,
+    depth: usize,
+    output: &mut String,
+) -> Result<()> {
+    if args.level.is_some_and(|max| depth >= max) {
+        return Ok(());
+    }
+
+    let mut entries_paths = match read_dir_filtered(dir, args.all) {
+        Ok(entries_paths) => entries_paths,
+        Err(e) => {
+            output.push_str(&format!("{prefix}└── [error opening directory: {e}]\n"));
+            return Ok(());
+        }
+    };
+    sort_entries(&mut entries_paths, args.sort_key(), args.reverse);
+
+    let last_index = entries_paths.len().saturating_sub(1);
+    for (i, entry) in entries_paths.iter().enumerate() {
+        let is_last = i == last_index;
+        let connector = if is_last { "└── " } else { "├── " };
+        let name = entry.file_name().unwrap().to_str().unwrap();
+
+        output.push_str(&format!("{prefix}{connector}{name}\n"));
+
+        if entry.is_dir() {
+            let child_prefix = format!("{prefix}{}", if is_last { "   " } else { "│  " });
+            render_tree(entry, args, git_statuses, &child_prefix, depth + 1, output)?;
+        }
+    }
+
+    Ok(())
+}
+
 fn ls(args: Args) -> Result<String> {
-    let path = args.path;
+    let path = args.path.clone();
 
     // If the provided path is not a directory just print the name.
     if !fs::metadata(&path)?.is_dir() {
         return Ok(path);
     }
 
-    let mut entries_paths = {
-        let all_files = fs::read_dir(path)?.map(|entry| entry.unwrap().path());
+    let git_statuses = args
+        .git
+        .then(|| GitStatuses::discover(Path::new(&path)))
+        .flatten();
 
-        if args.all {
-            all_files.collect::<Vec<_>>()
-        } else {
-            all_files
-                .filter(|path| {
-                    path.file_name()
-                        .and_then(|name| name.to_str())
-                        .map_or(false, |name| !name.starts_with('.'))
-                })
-                .collect::<Vec<_>>()
-        }
-    };
+    if args.tree {
+        let mut output = String::new();
+        render_tree(Path::new(&path), &args, &git_statuses, "", 0, &mut output)?;
+        return Ok(output.trim_end_matches('\n').to_string());
+    }
 
-    entries_paths.sort();
+    let mut entries_paths = read_dir_filtered(Path::new(&path), args.all)?;
+
+    sort_entries(&mut entries_paths, args.sort_key(), args.reverse);
     let entries_strs = entries_paths
         .iter()
         .map(|e| e.to_str().unwrap().to_string())
@@ -140,58 +552,30 @@ fn ls(args: Args) -> Result<String> {
     if args.list {
         #[cfg(unix)]
         {
-            let metadatas = entries_paths
-                .iter()
-                .map(|entry| fs::metadata(entry).unwrap())
-                .collect::<Vec<fs::Metadata>>();
-            let modes = metadatas.iter().map(get_mode).collect::<Vec<String>>();
-            let nlinks = metadatas.iter().map(get_nlink).collect::<Vec<String>>();
-            let owners = metadatas
-                .iter()
-                .map(|metadata| get_file_owner(metadata).unwrap())
-                .collect::<Vec<String>>();
-            let groups = metadatas
-                .iter()
-                .map(|metadata| get_file_group(metadata).unwrap())
-                .collect::<Vec<String>>();
-            let sizes = metadatas
-                .iter()
-                .map(|m| {
-                    if args.human {
-                        get_size_human_readable(m)
-                    } else {
-                        get_size_bytes(m)
-                    }
-                })
-                .collect::<Vec<String>>();
-            let last_modified = metadatas
-                .iter()
-                .map(|metadata| get_last_modified(metadata).unwrap())
-                .collect::<Vec<String>>();
-
-            let max_nlink_width = nlinks.iter().map(|nlink| nlink.len()).max().unwrap_or(0);
-            let max_owner_width = owners.iter().map(|owner| owner.len()).max().unwrap_or(0);
-            let max_group_width = groups.iter().map(|group| group.len()).max().unwrap_or(0);
-            let max_size_width = sizes.iter().map(|size| size.len()).max().unwrap_or(0);
+            let metas = gather_entry_metas(&entries_paths, &args);
+            let widths = column_widths(&metas);
 
             Ok(entries_paths
-                .into_iter()
-                .enumerate()
-                .map(|(i, entry)| {
-                    format!("{:<10} {:>width$} {:<owner_width$} {:<group_width$} {:>size_width$} {} {entry}",
-                            modes[i], nlinks[i], owners[i], groups[i], sizes[i], last_modified[i],
-                            entry = entry.file_name().unwrap().to_str().unwrap(),
-                            width = max_nlink_width,
-                            owner_width = max_owner_width,
-                            group_width = max_group_width,
-                            size_width = max_size_width)
+                .iter()
+                .zip(metas.iter())
+                .map(|(entry, meta)| {
+                    let is_symlink = fs::symlink_metadata(entry)
+                        .map(|m| m.is_symlink())
+                        .unwrap_or(false);
+                    let name = entry.file_name().unwrap().to_str().unwrap();
+
+                    format!(
+                        "{}{}",
+                        format_metadata_block(entry, meta, &widths, args.git, &git_statuses),
+                        colorize_name(name, &meta.metadata, is_symlink)
+                    )
                 })
                 .collect::<Vec<String>>()
                 .join("\n"))
         }
 
         #[cfg(not(unix))]
-        Ok(entries_paths.join("\n"))
+        Ok(entries_strs.join("\n"))
     } else {
         // Iterate over the terminal width
         let terminal_width = size()?.0 as usize;